@@ -1,18 +1,116 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use std::{
+    backtrace::Backtrace,
     env,
     fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
     path::{Path, PathBuf},
     process::{Child, Command, Stdio},
-    sync::{Arc, Mutex},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
-use anyhow::{Context, Result};
-use tauri::{AppHandle, Manager, RunEvent};
+use anyhow::{anyhow, Context, Result};
+use serde::Serialize;
+use tauri::{
+    api::process::{Command as SidecarCommand, CommandChild, CommandEvent},
+    AppHandle, Manager, RunEvent,
+};
 use which::which;
 
 const DEFAULT_PORT: u16 = 1421;
+const DEFAULT_SHUTDOWN_TIMEOUT_MS: u64 = 5000;
+const HEALTH_CHECK_INTERVAL_MS: u64 = 2000;
+const HEALTH_CHECK_FAILURE_THRESHOLD: u32 = 3;
+const MAX_RESTART_ATTEMPTS: u32 = 5;
+const RESTART_BACKOFF_BASE_MS: u64 = 500;
+const MINIMUM_PYTHON_VERSION: (u32, u32) = (3, 10);
+const PORT_SCAN_RANGE: u16 = 20;
+const SIDECAR_NAME: &str = "oratioviva-backend";
+
+/// Either a directly-spawned `python server.py` process (debug builds, or
+/// release builds without a bundled backend) or a Tauri-managed sidecar
+/// (packaged release builds). `exited` is set by the sidecar's event-reader
+/// task, since `CommandChild` has no `try_wait()` of its own.
+enum BackendChild {
+    Direct(Child),
+    Sidecar {
+        child: Option<CommandChild>,
+        exited: Arc<AtomicBool>,
+    },
+}
+
+impl BackendChild {
+    fn pid(&self) -> Option<u32> {
+        match self {
+            BackendChild::Direct(child) => Some(child.id()),
+            BackendChild::Sidecar { child, .. } => child.as_ref().map(|c| c.pid()),
+        }
+    }
+
+    fn has_exited(&mut self) -> bool {
+        match self {
+            BackendChild::Direct(child) => matches!(child.try_wait(), Ok(Some(_))),
+            BackendChild::Sidecar { exited, .. } => exited.load(Ordering::SeqCst),
+        }
+    }
+
+    /// Kills the process (if still alive) and blocks until it has actually
+    /// gone away, so callers can rely on the process tree being clean.
+    fn kill_and_wait(&mut self) {
+        match self {
+            BackendChild::Direct(child) => {
+                let _ = child.kill();
+                let _ = child.wait();
+            }
+            BackendChild::Sidecar { child, exited } => {
+                if let Some(child) = child.take() {
+                    let _ = child.kill();
+                }
+                while !exited.load(Ordering::SeqCst) {
+                    thread::sleep(Duration::from_millis(50));
+                }
+            }
+        }
+    }
+}
+
+/// Tracks the spawned backend process together with the host/port it was
+/// told to bind, so the exit handler can ask it to drain before killing it.
+struct BackendHandle {
+    child: BackendChild,
+    host: String,
+    port: u16,
+}
+
+/// A line of backend stdout/stderr, forwarded to the frontend as it is
+/// captured so a debug console can tail the backend live.
+#[derive(Clone, Serialize)]
+struct BackendLogLine {
+    stream: &'static str,
+    line: String,
+}
+
+/// Payload for `backend://up`: carries the currently bound port, since a
+/// restart re-runs `allocate_port` and can land on a different one than the
+/// original launch, leaving a cached `backend_port()` answer stale.
+#[derive(Clone, Serialize)]
+struct BackendUpEvent {
+    port: u16,
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
 
 fn find_backend_script(app: &AppHandle) -> Option<PathBuf> {
     let mut candidates = Vec::new();
@@ -33,74 +131,565 @@ fn find_project_root(script: &Path) -> Option<PathBuf> {
     script.parent().and_then(Path::parent).map(Path::to_path_buf)
 }
 
-fn find_python(root: &Path) -> Result<PathBuf> {
-    let candidates = [
+/// Lists interpreters worth probing, in priority order: the project's own
+/// `.venv` first, then a PATH scan preferring `python` over `python3` over
+/// `python2` (mirroring how a shell itself would resolve the name).
+fn discover_python_candidates(root: &Path) -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+
+    for venv_python in [
         root.join(".venv").join("Scripts").join("python.exe"),
         root.join(".venv").join("bin").join("python"),
-    ];
-    for cand in candidates {
-        if cand.exists() {
-            return Ok(cand);
+    ] {
+        if venv_python.exists() {
+            candidates.push(venv_python);
+        }
+    }
+
+    for name in ["python", "python3", "python2"] {
+        if let Ok(found) = which(name) {
+            candidates.push(found);
         }
     }
 
-    which("python")
-        .or_else(|_| which("python3"))
-        .context("Python introuvable (ni .venv ni PATH)")
+    candidates
 }
 
-fn spawn_backend(app: &AppHandle) -> Result<Child> {
-    let script = find_backend_script(app).context("backend/server.py introuvable")?;
-    let project_root = find_project_root(&script).context("Impossible de determiner la racine du projet")?;
-    let python = find_python(&project_root)?;
+/// Runs `python -c "..."` to read `sys.version_info` without relying on the
+/// `--version` flag, whose output stream (stdout vs stderr) differs across
+/// Python 2 and Python 3.
+fn probe_python_version(python: &Path) -> Option<(u32, u32)> {
+    let output = Command::new(python)
+        .arg("-c")
+        .arg("import sys; print('%d.%d' % (sys.version_info[0], sys.version_info[1]))")
+        .output()
+        .ok()?;
 
-    let port = env::var("ORATIO_PORT")
+    if !output.status.success() {
+        return None;
+    }
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    let mut parts = text.trim().split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+fn find_python(root: &Path) -> Result<PathBuf> {
+    let candidates = discover_python_candidates(root);
+    if candidates.is_empty() {
+        return Err(anyhow!(
+            "Python introuvable (ni .venv ni PATH): installez Python {}.{}+ et reessayez",
+            MINIMUM_PYTHON_VERSION.0,
+            MINIMUM_PYTHON_VERSION.1
+        ));
+    }
+
+    let mut rejected = Vec::new();
+    for candidate in candidates {
+        match probe_python_version(&candidate) {
+            Some(version) if version >= MINIMUM_PYTHON_VERSION => return Ok(candidate),
+            Some((major, minor)) => {
+                rejected.push(format!("{} (Python {major}.{minor})", candidate.display()))
+            }
+            None => rejected.push(format!("{} (version illisible)", candidate.display())),
+        }
+    }
+
+    Err(anyhow!(
+        "Aucun interpreteur Python {}.{}+ trouve. Candidats rejetes: {}",
+        MINIMUM_PYTHON_VERSION.0,
+        MINIMUM_PYTHON_VERSION.1,
+        rejected.join(", ")
+    ))
+    .context("Echec de la detection de Python")
+}
+
+/// Resolves a usable port for the backend: the requested port if it is
+/// still free, otherwise the next free port within `PORT_SCAN_RANGE`, and
+/// failing that an OS-assigned ephemeral port.
+fn allocate_port(host: &str, requested: u16) -> Result<u16> {
+    if TcpListener::bind((host, requested)).is_ok() {
+        return Ok(requested);
+    }
+
+    println!("[oratioviva-tauri] port {requested} deja utilise, recherche d'un port libre...");
+    for candidate in requested.saturating_add(1)..=requested.saturating_add(PORT_SCAN_RANGE) {
+        if TcpListener::bind((host, candidate)).is_ok() {
+            return Ok(candidate);
+        }
+    }
+
+    TcpListener::bind((host, 0))
+        .and_then(|listener| listener.local_addr().map(|addr| addr.port()))
+        .context("Impossible de reserver un port pour le backend")
+}
+
+/// Host/port/data-dir resolution shared by both launch modes.
+struct BackendLaunchContext {
+    host: String,
+    port: u16,
+    data_dir: PathBuf,
+}
+
+fn prepare_launch_context(app: &AppHandle, fallback_root: &Path) -> Result<BackendLaunchContext> {
+    let requested_port = env::var("ORATIO_PORT")
         .ok()
         .and_then(|p| p.parse().ok())
         .unwrap_or(DEFAULT_PORT);
     let host = env::var("ORATIO_HOST").unwrap_or_else(|_| "127.0.0.1".to_string());
+    let port = allocate_port(&host, requested_port)?;
 
     let data_dir = app
         .path_resolver()
         .app_data_dir()
-        .unwrap_or_else(|| project_root.join("data"));
+        .unwrap_or_else(|| fallback_root.join("data"));
     fs::create_dir_all(&data_dir)?;
 
+    Ok(BackendLaunchContext { host, port, data_dir })
+}
+
+/// Launches the backend: a bundled sidecar executable in packaged release
+/// builds (tracked and terminated by Tauri itself), falling back to
+/// `python server.py` against the checked-out source tree everywhere else.
+/// Sidecar mode is gated by actually trying `new_sidecar` rather than
+/// probing for the binary under `resource_dir` first: where exactly an
+/// `externalBin` lands varies by platform/bundler, so a resource-dir probe
+/// can go stale and silently defeat the single-installer goal.
+fn spawn_backend(app: &AppHandle) -> Result<BackendHandle> {
+    if let Some(handle) = try_spawn_sidecar_backend(app) {
+        return Ok(handle);
+    }
+    spawn_source_backend(app)
+}
+
+#[cfg(debug_assertions)]
+fn try_spawn_sidecar_backend(_app: &AppHandle) -> Option<BackendHandle> {
+    None
+}
+
+#[cfg(not(debug_assertions))]
+fn try_spawn_sidecar_backend(app: &AppHandle) -> Option<BackendHandle> {
+    match spawn_sidecar_backend(app) {
+        Ok(handle) => Some(handle),
+        Err(err) => {
+            eprintln!(
+                "[oratioviva-tauri] sidecar embarque indisponible ({err:?}), bascule vers le mode source"
+            );
+            None
+        }
+    }
+}
+
+fn spawn_source_backend(app: &AppHandle) -> Result<BackendHandle> {
+    let script = find_backend_script(app).context("backend/server.py introuvable")?;
+    let project_root = find_project_root(&script).context("Impossible de determiner la racine du projet")?;
+    let python = find_python(&project_root)?;
+    let ctx = prepare_launch_context(app, &project_root)?;
+
     println!(
-        "[oratioviva-tauri] lancement backend: {} {}:{} (cwd: {})",
+        "[oratioviva-tauri] lancement backend (source): {} {}:{} (cwd: {})",
         python.display(),
-        host,
-        port,
+        ctx.host,
+        ctx.port,
         project_root.display()
     );
 
     let mut cmd = Command::new(python);
     cmd.arg(&script)
         .arg("--host")
-        .arg(&host)
+        .arg(&ctx.host)
         .arg("--port")
-        .arg(port.to_string())
+        .arg(ctx.port.to_string())
         .current_dir(&project_root)
-        .env("ORATIO_DATA_DIR", &data_dir)
-        .env("ORATIO_HOST", &host)
-        .env("ORATIO_PORT", port.to_string())
+        .env("ORATIO_DATA_DIR", &ctx.data_dir)
+        .env("ORATIO_HOST", &ctx.host)
+        .env("ORATIO_PORT", ctx.port.to_string())
         .stdin(Stdio::null())
-        .stdout(Stdio::null())
-        .stderr(Stdio::null());
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    let mut child = cmd.spawn().context("Echec du demarrage du backend")?;
 
-    let child = cmd.spawn().context("Echec du demarrage du backend")?;
-    Ok(child)
+    let logs_dir = ctx.data_dir.join("logs");
+    fs::create_dir_all(&logs_dir)?;
+    let timestamp = unix_timestamp();
+
+    if let Some(stdout) = child.stdout.take() {
+        spawn_output_reader(
+            app.clone(),
+            "stdout",
+            stdout,
+            logs_dir.join(format!("backend-{timestamp}-stdout.log")),
+        );
+    }
+    if let Some(stderr) = child.stderr.take() {
+        spawn_output_reader(
+            app.clone(),
+            "stderr",
+            stderr,
+            logs_dir.join(format!("backend-{timestamp}-stderr.log")),
+        );
+    }
+
+    Ok(BackendHandle {
+        child: BackendChild::Direct(child),
+        host: ctx.host,
+        port: ctx.port,
+    })
+}
+
+/// Launches the bundled backend through Tauri's sidecar process API, which
+/// the framework itself tracks and terminates. Output arrives as
+/// `CommandEvent`s on a channel rather than raw pipes, so it is tailed from
+/// an async task instead of `spawn_output_reader`'s blocking thread.
+fn spawn_sidecar_backend(app: &AppHandle) -> Result<BackendHandle> {
+    let fallback_root = env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    let ctx = prepare_launch_context(app, &fallback_root)?;
+
+    println!(
+        "[oratioviva-tauri] lancement backend (sidecar embarque) {}:{}",
+        ctx.host, ctx.port
+    );
+
+    let (mut rx, child) = SidecarCommand::new_sidecar(SIDECAR_NAME)
+        .context("sidecar backend non declare dans tauri.conf.json")?
+        .args(["--host", &ctx.host, "--port", &ctx.port.to_string()])
+        .envs([
+            ("ORATIO_DATA_DIR", ctx.data_dir.display().to_string()),
+            ("ORATIO_HOST", ctx.host.clone()),
+            ("ORATIO_PORT", ctx.port.to_string()),
+        ])
+        .spawn()
+        .context("Echec du demarrage du backend embarque")?;
+
+    let logs_dir = ctx.data_dir.join("logs");
+    fs::create_dir_all(&logs_dir)?;
+    let log_path = logs_dir.join(format!("backend-{}-sidecar.log", unix_timestamp()));
+    let exited = Arc::new(AtomicBool::new(false));
+    let exited_events = exited.clone();
+    let app_events = app.clone();
+
+    tauri::async_runtime::spawn(async move {
+        let mut log_file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&log_path)
+            .ok();
+
+        while let Some(event) = rx.recv().await {
+            match event {
+                CommandEvent::Stdout(line) | CommandEvent::Stderr(line) => {
+                    if let Some(file) = log_file.as_mut() {
+                        let _ = writeln!(file, "{line}");
+                        let _ = file.flush();
+                    }
+                    let _ = app_events.emit_all(
+                        "backend://log",
+                        BackendLogLine {
+                            stream: "sidecar",
+                            line,
+                        },
+                    );
+                }
+                CommandEvent::Terminated(_) | CommandEvent::Error(_) => {
+                    exited_events.store(true, Ordering::SeqCst);
+                }
+                _ => {}
+            }
+        }
+        exited_events.store(true, Ordering::SeqCst);
+    });
+
+    Ok(BackendHandle {
+        child: BackendChild::Sidecar {
+            child: Some(child),
+            exited,
+        },
+        host: ctx.host,
+        port: ctx.port,
+    })
+}
+
+/// Tails a backend stdout/stderr pipe on its own thread: every line is
+/// appended to `log_path` and forwarded to the frontend as a
+/// `backend://log` event, so a diagnostic trail survives even when nobody
+/// is watching the window.
+fn spawn_output_reader(
+    app: AppHandle,
+    stream_name: &'static str,
+    reader: impl Read + Send + 'static,
+    log_path: PathBuf,
+) {
+    thread::spawn(move || {
+        let mut log_file = match fs::OpenOptions::new().create(true).append(true).open(&log_path) {
+            Ok(file) => file,
+            Err(err) => {
+                eprintln!(
+                    "[oratioviva-tauri] impossible d'ouvrir {}: {err}",
+                    log_path.display()
+                );
+                return;
+            }
+        };
+
+        for line in BufReader::new(reader).lines().flatten() {
+            let _ = writeln!(log_file, "{line}");
+            let _ = log_file.flush();
+            let _ = app.emit_all(
+                "backend://log",
+                BackendLogLine {
+                    stream: stream_name,
+                    line,
+                },
+            );
+        }
+    });
+}
+
+/// Sends a best-effort graceful-shutdown signal to the backend: SIGTERM on
+/// Unix (so the Python process can run its own shutdown handlers), then an
+/// HTTP POST to `/shutdown`, which is sent on every platform.
+fn request_graceful_shutdown(pid: Option<u32>, host: &str, port: u16) {
+    #[cfg(unix)]
+    {
+        if let Some(pid) = pid {
+            let _ = Command::new("kill").arg("-TERM").arg(pid.to_string()).status();
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = pid;
+    }
+
+    let _ = post_shutdown(host, port);
+}
+
+fn post_shutdown(host: &str, port: u16) -> std::io::Result<()> {
+    let mut stream = TcpStream::connect((host, port))?;
+    let request = format!(
+        "POST /shutdown HTTP/1.1\r\nHost: {host}:{port}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+    );
+    stream.write_all(request.as_bytes())
+}
+
+/// Drains and waits for the backend to exit. Sends the graceful-shutdown
+/// signal, then polls `try_wait()` until either the child reaps or
+/// `ORATIO_SHUTDOWN_TIMEOUT_MS` (default 5000ms) elapses, at which point it
+/// is force-killed. Blocks the caller until the child has been reaped so the
+/// process tree is clean before Tauri exits.
+fn shutdown_backend(mut handle: BackendHandle) {
+    request_graceful_shutdown(handle.child.pid(), &handle.host, handle.port);
+
+    let timeout_ms = env::var("ORATIO_SHUTDOWN_TIMEOUT_MS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_SHUTDOWN_TIMEOUT_MS);
+    let deadline = Instant::now() + Duration::from_millis(timeout_ms);
+
+    loop {
+        if handle.child.has_exited() {
+            return;
+        }
+        if Instant::now() >= deadline {
+            println!("[oratioviva-tauri] le backend n'a pas quitte a temps, arret force");
+            handle.child.kill_and_wait();
+            return;
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+}
+
+/// Performs a single HTTP GET against the backend and reports whether it
+/// answered with a 2xx status line. Used by the supervisor to decide if the
+/// process is actually serving traffic, not just still running.
+fn probe_health(host: &str, port: u16) -> bool {
+    http_get_status_ok(host, port, "/health").unwrap_or(false)
+}
+
+fn http_get_status_ok(host: &str, port: u16, path: &str) -> std::io::Result<bool> {
+    let mut stream = TcpStream::connect((host, port))?;
+    stream.set_read_timeout(Some(Duration::from_millis(1000)))?;
+    let request = format!("GET {path} HTTP/1.1\r\nHost: {host}:{port}\r\nConnection: close\r\n\r\n");
+    stream.write_all(request.as_bytes())?;
+
+    let mut buf = [0u8; 32];
+    let n = stream.read(&mut buf)?;
+    let status_line = String::from_utf8_lossy(&buf[..n]);
+    Ok(status_line.starts_with("HTTP/1.1 2") || status_line.starts_with("HTTP/1.0 2"))
+}
+
+/// Background supervisor: periodically checks that the backend process is
+/// both still running (`try_wait`) and actually answering health checks. If
+/// it stays unhealthy for `HEALTH_CHECK_FAILURE_THRESHOLD` consecutive
+/// probes, it is killed and respawned with exponential backoff, up to
+/// `MAX_RESTART_ATTEMPTS`. Emits `backend://down` / `backend://up` so the
+/// frontend can show a reconnection banner.
+fn supervise_backend(
+    app: AppHandle,
+    backend_proc: Arc<Mutex<Option<BackendHandle>>>,
+    shutting_down: Arc<AtomicBool>,
+) {
+    thread::spawn(move || {
+        let mut consecutive_failures = 0u32;
+        let mut restart_attempt = 0u32;
+        // Set once a restart has been issued; cleared the moment the
+        // respawned backend proves itself healthy, which is also when
+        // `restart_attempt` gets to reset. This keeps the cap a per-incident
+        // one (each fresh run of failures gets its own `MAX_RESTART_ATTEMPTS`
+        // budget) instead of a lifetime counter that never comes back down.
+        let mut awaiting_restart_confirmation = false;
+
+        loop {
+            thread::sleep(Duration::from_millis(HEALTH_CHECK_INTERVAL_MS));
+            if shutting_down.load(Ordering::SeqCst) {
+                return;
+            }
+
+            let (alive, host, port) = {
+                let mut guard = backend_proc.lock().unwrap();
+                match guard.as_mut() {
+                    Some(handle) => (!handle.child.has_exited(), handle.host.clone(), handle.port),
+                    None => (false, String::new(), 0),
+                }
+            };
+
+            let healthy = alive && probe_health(&host, port);
+            if healthy {
+                if awaiting_restart_confirmation {
+                    restart_attempt = 0;
+                    awaiting_restart_confirmation = false;
+                }
+                if consecutive_failures > 0 {
+                    consecutive_failures = 0;
+                    let _ = app.emit_all("backend://up", BackendUpEvent { port });
+                }
+                continue;
+            }
+
+            consecutive_failures += 1;
+            if consecutive_failures < HEALTH_CHECK_FAILURE_THRESHOLD {
+                continue;
+            }
+
+            if restart_attempt >= MAX_RESTART_ATTEMPTS {
+                eprintln!(
+                    "[oratioviva-tauri] backend indisponible, nombre maximal de redemarrages atteint"
+                );
+                continue;
+            }
+
+            let _ = app.emit_all("backend://down", ());
+
+            let old = backend_proc.lock().unwrap().take();
+            if let Some(mut old) = old {
+                old.child.kill_and_wait();
+            }
+
+            let backoff_ms = RESTART_BACKOFF_BASE_MS * 2u64.pow(restart_attempt);
+            thread::sleep(Duration::from_millis(backoff_ms));
+            restart_attempt += 1;
+
+            if shutting_down.load(Ordering::SeqCst) {
+                // The user quit while we were backing off: the exit handler
+                // already found `backend_proc` empty and has nothing left to
+                // drain, so don't leave a fresh orphan behind.
+                return;
+            }
+
+            match spawn_backend(&app) {
+                Ok(mut handle) => {
+                    let new_port = handle.port;
+                    // Check `shutting_down` and store the handle under the
+                    // same lock: if we dropped the lock between the check and
+                    // the store, the exit handler could `take()` an empty
+                    // `backend_proc` in between and this freshly spawned
+                    // child would never be drained on quit.
+                    let mut guard = backend_proc.lock().unwrap();
+                    if shutting_down.load(Ordering::SeqCst) {
+                        drop(guard);
+                        handle.child.kill_and_wait();
+                        return;
+                    }
+                    *guard = Some(handle);
+                    drop(guard);
+
+                    println!("[oratioviva-tauri] backend redemarre (tentative {restart_attempt})");
+                    consecutive_failures = 0;
+                    awaiting_restart_confirmation = true;
+                    let _ = app.emit_all("backend://up", BackendUpEvent { port: new_port });
+                }
+                Err(err) => {
+                    eprintln!("[oratioviva-tauri] echec du redemarrage du backend: {err:?}");
+                }
+            }
+        }
+    });
+}
+
+/// Installs a panic hook that appends the panic message and a backtrace to
+/// `logs/tauri-crash.log` under the app data dir before chaining to the
+/// default hook, so a crashing Rust shell still leaves a debuggable trail
+/// next to the backend's own logs.
+fn install_panic_hook(app: &AppHandle) {
+    let data_dir = app
+        .path_resolver()
+        .app_data_dir()
+        .unwrap_or_else(|| PathBuf::from("data"));
+    let logs_dir = data_dir.join("logs");
+
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if fs::create_dir_all(&logs_dir).is_ok() {
+            if let Ok(mut file) = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(logs_dir.join("tauri-crash.log"))
+            {
+                let _ = writeln!(
+                    file,
+                    "--- panic at {} ---\n{info}\n{:?}\n",
+                    unix_timestamp(),
+                    Backtrace::force_capture()
+                );
+                let _ = file.flush();
+            }
+        }
+        default_hook(info);
+    }));
+}
+
+/// Exposes the port actually bound by the backend (which may differ from
+/// `ORATIO_PORT` if that one was occupied) so the webview can connect to
+/// the right URL.
+#[tauri::command]
+fn backend_port(state: tauri::State<Arc<Mutex<Option<BackendHandle>>>>) -> u16 {
+    state
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|handle| handle.port)
+        .unwrap_or(DEFAULT_PORT)
 }
 
 fn main() {
-    let backend_proc: Arc<Mutex<Option<Child>>> = Arc::new(Mutex::new(None));
+    let backend_proc: Arc<Mutex<Option<BackendHandle>>> = Arc::new(Mutex::new(None));
+    let backend_proc_setup = backend_proc.clone();
     let backend_proc_run = backend_proc.clone();
+    let shutting_down: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+    let shutting_down_run = shutting_down.clone();
 
     tauri::Builder::default()
+        .manage(backend_proc.clone())
+        .invoke_handler(tauri::generate_handler![backend_port])
         .setup(move |app| {
+            install_panic_hook(app);
             match spawn_backend(app) {
-                Ok(child) => {
-                    *backend_proc.lock().unwrap() = Some(child);
+                Ok(handle) => {
+                    *backend_proc_setup.lock().unwrap() = Some(handle);
+                    supervise_backend(app.handle(), backend_proc_setup.clone(), shutting_down.clone());
                 }
                 Err(err) => {
                     eprintln!("[oratioviva-tauri] backend non demarre: {err:?}");
@@ -112,8 +701,9 @@ fn main() {
         .expect("Erreur au demarrage de Tauri")
         .run(move |_app_handle, event| {
             if matches!(event, RunEvent::ExitRequested { .. } | RunEvent::Exit) {
-                if let Some(mut child) = backend_proc_run.lock().unwrap().take() {
-                    let _ = child.kill();
+                shutting_down_run.store(true, Ordering::SeqCst);
+                if let Some(handle) = backend_proc_run.lock().unwrap().take() {
+                    shutdown_backend(handle);
                 }
             }
         });